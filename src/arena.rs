@@ -0,0 +1,318 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+struct NodeData<T> {
+    parent: Option<NodeId>,
+    first_child: Option<NodeId>,
+    last_child: Option<NodeId>,
+    prev_sibling: Option<NodeId>,
+    next_sibling: Option<NodeId>,
+
+    value: T,
+}
+
+enum Slot<T> {
+    Free(Option<NodeId>),
+    Occupied(NodeData<T>),
+}
+
+pub struct Arena<T> {
+    nodes: Vec<Slot<T>>,
+    free_head: Option<NodeId>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Arena {
+            nodes: Vec::new(),
+            free_head: None,
+        }
+    }
+
+    pub fn new_node(&mut self, value: T) -> NodeId {
+        let data = NodeData {
+            parent: None,
+            first_child: None,
+            last_child: None,
+            prev_sibling: None,
+            next_sibling: None,
+            value,
+        };
+
+        if let Some(id) = self.free_head {
+            let Slot::Free(next_free) = &self.nodes[id.0] else {
+                unreachable!("free list pointed at an occupied slot");
+            };
+            self.free_head = *next_free;
+            self.nodes[id.0] = Slot::Occupied(data);
+            id
+        } else {
+            let id = NodeId(self.nodes.len());
+            self.nodes.push(Slot::Occupied(data));
+            id
+        }
+    }
+
+    fn node(&self, id: NodeId) -> &NodeData<T> {
+        match &self.nodes[id.0] {
+            Slot::Occupied(data) => data,
+            Slot::Free(_) => panic!("NodeId refers to a detached, reclaimed slot"),
+        }
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut NodeData<T> {
+        match &mut self.nodes[id.0] {
+            Slot::Occupied(data) => data,
+            Slot::Free(_) => panic!("NodeId refers to a detached, reclaimed slot"),
+        }
+    }
+
+    pub fn get(&self, id: NodeId) -> &T {
+        &self.node(id).value
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.node_mut(id).value
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub fn first_child(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).first_child
+    }
+
+    pub fn last_child(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).last_child
+    }
+
+    pub fn prev_sibling(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).prev_sibling
+    }
+
+    pub fn next_sibling(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).next_sibling
+    }
+
+    pub fn is_root(&self, id: NodeId) -> bool {
+        self.node(id).parent.is_none()
+    }
+
+    pub fn append(&mut self, parent: NodeId, node: NodeId) {
+        let prev = self.node(parent).last_child;
+        self.attach(parent, prev, None, node);
+    }
+
+    pub fn prepend(&mut self, parent: NodeId, node: NodeId) {
+        let next = self.node(parent).first_child;
+        self.attach(parent, None, next, node);
+    }
+
+    pub fn insert_after(&mut self, sibling: NodeId, node: NodeId) {
+        let parent = self
+            .node(sibling)
+            .parent
+            .expect("Cannot attach node as sibling to a root node");
+        let next = self.node(sibling).next_sibling;
+        self.attach(parent, Some(sibling), next, node);
+    }
+
+    pub fn insert_before(&mut self, sibling: NodeId, node: NodeId) {
+        let parent = self
+            .node(sibling)
+            .parent
+            .expect("Cannot attach node as sibling to a root node");
+        let prev = self.node(sibling).prev_sibling;
+        self.attach(parent, prev, Some(sibling), node);
+    }
+
+    fn attach(&mut self, parent: NodeId, prev: Option<NodeId>, next: Option<NodeId>, node: NodeId) {
+        debug_assert!(self.is_root(node));
+
+        if let Some(prev) = prev {
+            self.node_mut(prev).next_sibling = Some(node);
+        } else {
+            self.node_mut(parent).first_child = Some(node);
+        }
+
+        if let Some(next) = next {
+            self.node_mut(next).prev_sibling = Some(node);
+        } else {
+            self.node_mut(parent).last_child = Some(node);
+        }
+
+        self.node_mut(node).parent = Some(parent);
+        self.node_mut(node).prev_sibling = prev;
+        self.node_mut(node).next_sibling = next;
+    }
+
+    pub fn detach(&mut self, node: NodeId) {
+        let data = self.node(node);
+        let (parent, prev_sibling, next_sibling) = (data.parent, data.prev_sibling, data.next_sibling);
+
+        let Some(parent) = parent else {
+            return;
+        };
+
+        if let Some(prev) = prev_sibling {
+            self.node_mut(prev).next_sibling = next_sibling;
+        } else {
+            self.node_mut(parent).first_child = next_sibling;
+        }
+
+        if let Some(next) = next_sibling {
+            self.node_mut(next).prev_sibling = prev_sibling;
+        } else {
+            self.node_mut(parent).last_child = prev_sibling;
+        }
+
+        self.node_mut(node).parent = None;
+        self.node_mut(node).prev_sibling = None;
+        self.node_mut(node).next_sibling = None;
+    }
+
+    // Unlike `detach`, this discards the node and its whole subtree, reclaiming every slot they
+    // held so `new_node` can reuse them. Any `NodeId` into the removed subtree becomes stale and
+    // must not be used afterwards.
+    pub fn remove(&mut self, node: NodeId) {
+        self.detach(node);
+
+        let freed: Vec<NodeId> = self.descendants(node).collect();
+        for id in freed {
+            self.nodes[id.0] = Slot::Free(self.free_head);
+            self.free_head = Some(id);
+        }
+    }
+
+    pub fn children(&self, id: NodeId) -> Children<'_, T> {
+        Children {
+            arena: self,
+            node: self.first_child(id),
+        }
+    }
+
+    pub fn descendants(&self, id: NodeId) -> Descendants<'_, T> {
+        Descendants {
+            arena: self,
+            root: id,
+            node: Some(id),
+        }
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Children<'a, T> {
+    arena: &'a Arena<T>,
+    node: Option<NodeId>,
+}
+
+impl<'a, T> Iterator for Children<'a, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+        self.node = self.arena.next_sibling(node);
+        Some(node)
+    }
+}
+
+pub struct Descendants<'a, T> {
+    arena: &'a Arena<T>,
+    root: NodeId,
+    node: Option<NodeId>,
+}
+
+impl<'a, T> Iterator for Descendants<'a, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+
+        self.node = if let Some(first_child) = self.arena.first_child(node) {
+            Some(first_child)
+        } else {
+            let mut current = node;
+            loop {
+                if current == self.root {
+                    break None;
+                }
+
+                if let Some(next_sibling) = self.arena.next_sibling(current) {
+                    break Some(next_sibling);
+                }
+
+                match self.arena.parent(current) {
+                    Some(parent) => current = parent,
+                    None => break None,
+                }
+            }
+        };
+
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_and_descendants_are_preorder() {
+        let mut arena = Arena::new();
+        let root = arena.new_node(0);
+        let a = arena.new_node(1);
+        let b = arena.new_node(2);
+        let c = arena.new_node(3);
+
+        arena.append(root, a);
+        arena.append(root, b);
+        arena.append(a, c);
+
+        let children: Vec<i32> = arena.children(root).map(|id| *arena.get(id)).collect();
+        assert_eq!(children, vec![1, 2]);
+
+        let descendants: Vec<i32> = arena.descendants(root).map(|id| *arena.get(id)).collect();
+        assert_eq!(descendants, vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn detach_then_append_moves_a_node_without_panicking() {
+        let mut arena = Arena::new();
+        let root = arena.new_node(0);
+        let other = arena.new_node(1);
+        let child = arena.new_node(2);
+
+        arena.append(root, child);
+        arena.detach(child);
+
+        assert!(arena.is_root(child));
+
+        arena.append(other, child);
+
+        assert_eq!(arena.parent(child), Some(other));
+    }
+
+    #[test]
+    fn remove_reclaims_slots_for_new_nodes() {
+        let mut arena = Arena::new();
+        let root = arena.new_node(0);
+        let child = arena.new_node(1);
+        let grandchild = arena.new_node(2);
+
+        arena.append(root, child);
+        arena.append(child, grandchild);
+
+        arena.remove(child);
+
+        assert_eq!(arena.children(root).count(), 0);
+
+        let reused = arena.new_node(3);
+        assert_eq!(reused, grandchild);
+    }
+}
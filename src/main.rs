@@ -1,5 +1,7 @@
 use node::Node;
 
+#[allow(unused)]
+mod arena;
 #[allow(unused)]
 mod node;
 
@@ -12,13 +14,9 @@ fn main() {
     let c = Node::new("c");
     root.add_child_last(&c);
 
-    for child in root.children() {
-        println!("{}", **child);
-    }
+    println!("{:?}", root);
 
     b.detach();
 
-    for child in root.children() {
-        println!("{}", **child);
-    }
+    println!("{:?}", root);
 }
@@ -1,4 +1,5 @@
 use std::{
+    fmt,
     ops::{Deref, DerefMut},
     rc::{Rc, Weak},
 };
@@ -12,6 +13,21 @@ pub enum AttachTarget {
     Before,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    WouldCycle,
+}
+
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::WouldCycle => write!(f, "attaching this node would create a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
 pub struct Node<T> {
     // `Node<T>` behaves like it has strong references to its children, but it updates the strong
     // count to its children with `Rc::increment_strong_count` instead of having a
@@ -65,7 +81,8 @@ impl<T> Node<T> {
     }
 
     pub fn add_child_last(self: &Rc<Self>, node: &Rc<Self>) {
-        self.attach(AttachTarget::LastChild, node);
+        self.attach(AttachTarget::LastChild, node)
+            .expect("Attaching node would create a cycle");
     }
 
     pub fn remove_last_child(&self) -> Option<Rc<Self>> {
@@ -102,9 +119,17 @@ impl<T> Node<T> {
         }
     }
 
-    pub fn attach(self: &Rc<Self>, attach_target: AttachTarget, node: &Rc<Self>) {
+    pub fn attach(
+        self: &Rc<Self>,
+        attach_target: AttachTarget,
+        node: &Rc<Self>,
+    ) -> Result<(), TreeError> {
         assert!(node.is_root());
 
+        if self.would_cycle(node) {
+            return Err(TreeError::WouldCycle);
+        }
+
         match attach_target {
             AttachTarget::Before => {
                 let parent = self.parent.get().upgrade();
@@ -132,6 +157,8 @@ impl<T> Node<T> {
             Rc::increment_strong_count(Rc::as_ptr(node));
         }
 
+        return Ok(());
+
         fn _attach<T>(
             parent: Rc<Node<T>>,
             prev: Option<Rc<Node<T>>>,
@@ -158,17 +185,81 @@ impl<T> Node<T> {
         }
     }
 
+    pub fn reparent(
+        self: &Rc<Self>,
+        attach_target: AttachTarget,
+        node: &Rc<Self>,
+    ) -> Result<(), TreeError> {
+        if self.would_cycle(node) {
+            return Err(TreeError::WouldCycle);
+        }
+
+        if !node.is_root() {
+            node.detach();
+        }
+
+        self.attach(attach_target, node)
+    }
+
+    fn would_cycle(self: &Rc<Self>, node: &Rc<Self>) -> bool {
+        Rc::ptr_eq(self, node) || self.parents().any(|ancestor| Rc::ptr_eq(&ancestor, node))
+    }
+
     pub fn children(&self) -> Iter<T> {
         Iter {
             node: self.first_child().upgrade(),
         }
     }
 
+    pub fn children_rev(&self) -> IterRev<T> {
+        IterRev {
+            node: self.last_child.get().upgrade(),
+        }
+    }
+
     pub fn parents(&self) -> Parents<T> {
         Parents {
             node: self.parent().upgrade(),
         }
     }
+
+    pub fn following_siblings(self: &Rc<Self>) -> Iter<T> {
+        Iter {
+            node: Some(self.clone()),
+        }
+    }
+
+    pub fn preceding_siblings(self: &Rc<Self>) -> IterRev<T> {
+        IterRev {
+            node: Some(self.clone()),
+        }
+    }
+
+    pub fn descendants(self: &Rc<Self>) -> Descendants<T> {
+        Descendants {
+            root: self.clone(),
+            node: Some(self.clone()),
+        }
+    }
+
+    pub fn traverse(self: &Rc<Self>) -> Traverse<T> {
+        Traverse {
+            root: self.clone(),
+            next: Some(NodeEdge::Start(self.clone())),
+        }
+    }
+}
+
+impl<T: Clone> Node<T> {
+    pub fn make_deep_copy(self: &Rc<Self>) -> Rc<Self> {
+        let copy = Node::new(self.value.clone());
+
+        for child in self.children() {
+            copy.add_child_last(&child.make_deep_copy());
+        }
+
+        copy
+    }
 }
 
 impl<T> Drop for Node<T> {
@@ -183,6 +274,31 @@ impl<T> Drop for Node<T> {
     }
 }
 
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl<T: fmt::Debug> Node<T> {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        for _ in 0..depth {
+            write!(f, "  ")?;
+        }
+        writeln!(f, "{:?}", self.value)?;
+
+        if let Some(child) = self.first_child().upgrade() {
+            child.fmt_indented(f, depth + 1)?;
+        }
+
+        if let Some(sibling) = self.next_sibling().upgrade() {
+            sibling.fmt_indented(f, depth)?;
+        }
+
+        Ok(())
+    }
+}
+
 pub struct Iter<T> {
     node: Option<Rc<Node<T>>>,
 }
@@ -212,6 +328,21 @@ impl<T> Iterator for Iter<T> {
     }
 }
 
+pub struct IterRev<T> {
+    node: Option<Rc<Node<T>>>,
+}
+
+impl<T> Iterator for IterRev<T> {
+    type Item = Rc<Node<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.node.take().map(|node| {
+            self.node = node.prev_sibling().upgrade();
+            node
+        })
+    }
+}
+
 pub struct Parents<T> {
     node: Option<Rc<Node<T>>>,
 }
@@ -226,3 +357,229 @@ impl<T> Iterator for Parents<T> {
         })
     }
 }
+
+pub struct Descendants<T> {
+    root: Rc<Node<T>>,
+    node: Option<Rc<Node<T>>>,
+}
+
+impl<T> Iterator for Descendants<T> {
+    type Item = Rc<Node<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+
+        self.node = if let Some(first_child) = node.first_child().upgrade() {
+            Some(first_child)
+        } else {
+            let mut current = node.clone();
+            loop {
+                if Rc::ptr_eq(&current, &self.root) {
+                    break None;
+                }
+
+                if let Some(next_sibling) = current.next_sibling().upgrade() {
+                    break Some(next_sibling);
+                }
+
+                match current.parent().upgrade() {
+                    Some(parent) => current = parent,
+                    None => break None,
+                }
+            }
+        };
+
+        Some(node)
+    }
+}
+
+pub enum NodeEdge<T> {
+    Start(Rc<Node<T>>),
+    End(Rc<Node<T>>),
+}
+
+pub struct Traverse<T> {
+    root: Rc<Node<T>>,
+    next: Option<NodeEdge<T>>,
+}
+
+impl<T> Iterator for Traverse<T> {
+    type Item = NodeEdge<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+
+        self.next = match &next {
+            NodeEdge::Start(node) => match node.first_child().upgrade() {
+                Some(child) => Some(NodeEdge::Start(child)),
+                None => Some(NodeEdge::End(node.clone())),
+            },
+            NodeEdge::End(node) => {
+                if Rc::ptr_eq(node, &self.root) {
+                    None
+                } else {
+                    match node.next_sibling().upgrade() {
+                        Some(sibling) => Some(NodeEdge::Start(sibling)),
+                        None => node.parent().upgrade().map(NodeEdge::End),
+                    }
+                }
+            }
+        };
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_rejects_ancestor_cycle() {
+        let root = Node::new(0);
+        let child = Node::new(1);
+        let leaf = Node::new(2);
+
+        root.add_child_last(&child);
+        child.add_child_last(&leaf);
+
+        let result = leaf.attach(AttachTarget::FirstChild, &root);
+
+        assert_eq!(result, Err(TreeError::WouldCycle));
+        assert!(Rc::ptr_eq(&child.parent().upgrade().unwrap(), &root));
+        assert!(Rc::ptr_eq(&leaf.parent().upgrade().unwrap(), &child));
+    }
+
+    #[test]
+    fn reparent_moves_a_node_to_a_new_parent() {
+        let root = Node::new(0);
+        let a = Node::new(1);
+        let b = Node::new(2);
+        let leaf = Node::new(3);
+
+        root.add_child_last(&a);
+        root.add_child_last(&b);
+        a.add_child_last(&leaf);
+
+        b.reparent(AttachTarget::LastChild, &leaf).unwrap();
+
+        assert!(Rc::ptr_eq(&leaf.parent().upgrade().unwrap(), &b));
+        assert!(a.children().next().is_none());
+        assert!(Rc::ptr_eq(&b.children().next().unwrap(), &leaf));
+    }
+
+    #[test]
+    fn reparent_leaves_the_tree_unchanged_on_cycle_rejection() {
+        let root = Node::new(0);
+        let mid = Node::new(1);
+        let leaf = Node::new(2);
+
+        root.add_child_last(&mid);
+        mid.add_child_last(&leaf);
+
+        let result = leaf.reparent(AttachTarget::FirstChild, &mid);
+
+        assert_eq!(result, Err(TreeError::WouldCycle));
+        assert!(Rc::ptr_eq(&mid.parent().upgrade().unwrap(), &root));
+        assert!(Rc::ptr_eq(&root.children().next().unwrap(), &mid));
+        assert!(Rc::ptr_eq(&leaf.parent().upgrade().unwrap(), &mid));
+        assert!(Rc::ptr_eq(&mid.children().next().unwrap(), &leaf));
+    }
+
+    #[test]
+    fn descendants_are_preorder_depth_first() {
+        let root = Node::new(0);
+        let a = Node::new(1);
+        let b = Node::new(2);
+        let c = Node::new(3);
+
+        root.add_child_last(&a);
+        root.add_child_last(&b);
+        a.add_child_last(&c);
+
+        let values: Vec<i32> = root.descendants().map(|n| **n).collect();
+
+        assert_eq!(values, vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn traverse_emits_start_and_end_edges_in_order() {
+        let root = Node::new(0);
+        let a = Node::new(1);
+        let b = Node::new(2);
+        let c = Node::new(3);
+
+        root.add_child_last(&a);
+        root.add_child_last(&b);
+        a.add_child_last(&c);
+
+        let edges: Vec<(i32, bool)> = root
+            .traverse()
+            .map(|edge| match edge {
+                NodeEdge::Start(n) => (**n, true),
+                NodeEdge::End(n) => (**n, false),
+            })
+            .collect();
+
+        assert_eq!(
+            edges,
+            vec![
+                (0, true),
+                (1, true),
+                (3, true),
+                (3, false),
+                (1, false),
+                (2, true),
+                (2, false),
+                (0, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn make_deep_copy_clones_structure_and_values_independently() {
+        let root = Node::new(0);
+        let a = Node::new(1);
+        let b = Node::new(2);
+
+        root.add_child_last(&a);
+        a.add_child_last(&b);
+
+        let copy = root.make_deep_copy();
+
+        assert!(copy.is_root());
+        assert!(!Rc::ptr_eq(&copy, &root));
+
+        let copy_values: Vec<i32> = copy.descendants().map(|n| **n).collect();
+        assert_eq!(copy_values, vec![0, 1, 2]);
+
+        let copy_child = copy.children().next().unwrap();
+        assert!(!Rc::ptr_eq(&copy_child, &a));
+
+        b.detach();
+
+        let copy_values_after_detach: Vec<i32> = copy.descendants().map(|n| **n).collect();
+        assert_eq!(copy_values_after_detach, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn sibling_and_reverse_child_iterators() {
+        let root = Node::new(0);
+        let a = Node::new(1);
+        let b = Node::new(2);
+        let c = Node::new(3);
+
+        root.add_child_last(&a);
+        root.add_child_last(&b);
+        root.add_child_last(&c);
+
+        let following: Vec<i32> = a.following_siblings().map(|n| **n).collect();
+        assert_eq!(following, vec![1, 2, 3]);
+
+        let preceding: Vec<i32> = c.preceding_siblings().map(|n| **n).collect();
+        assert_eq!(preceding, vec![3, 2, 1]);
+
+        let rev_children: Vec<i32> = root.children_rev().map(|n| **n).collect();
+        assert_eq!(rev_children, vec![3, 2, 1]);
+    }
+}